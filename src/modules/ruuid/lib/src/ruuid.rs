@@ -2,12 +2,64 @@ extern crate libc;
 extern crate uuid;
 
 use std::{ptr, ffi::CStr, slice};
+use std::cell::Cell;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 use libc::{c_char, c_int, size_t};
 use uuid::Uuid;
 
 /// UUID namespace for SIP as defined in RFC 7989.
 const NAMESPACE_SIP: Uuid = uuid::uuid!("a58587da-c93d-11e2-ae90-f4ea67801e29");
 
+/// Number of 100-ns intervals between the Gregorian epoch (1582-10-15) and
+/// the Unix epoch (1970-01-01), used to decode v1/v6 timestamps.
+const GREGORIAN_EPOCH_OFFSET_100NS: u64 = 0x01B2_1DD2_1381_4000;
+
+thread_local! {
+    /// Last `(unix_millis, rand_a)` pair handed out by [`ruuid_generate_version_7()`]
+    /// on this thread, used to keep same-millisecond UUIDs monotonically increasing.
+    static LAST_V7: Cell<(u64, u16)> = const { Cell::new((0, 0)) };
+}
+
+/// Last `(gregorian_ticks, clock_seq)` pair handed out to a v1/v6 UUID,
+/// shared across threads so the clock sequence stays unique process-wide.
+static CLOCK_STATE: Mutex<(u64, u16)> = Mutex::new((0, 0));
+
+/// Current time as 100-ns ticks since the Gregorian epoch (1582-10-15), the
+/// timestamp basis used by UUID versions 1 and 6.
+fn gregorian_ticks_now() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    now.as_secs() * 10_000_000 + (now.subsec_nanos() as u64) / 100 + GREGORIAN_EPOCH_OFFSET_100NS
+}
+
+/// Take the current Gregorian timestamp together with the next clock
+/// sequence value, bumping the sequence whenever the timestamp does not
+/// move forward so concurrent calls within the same tick stay unique.
+///
+/// Both values are read and updated while holding `CLOCK_STATE`'s lock, so
+/// two threads can never observe or hand out the same `(ticks, seq)` pair.
+fn next_timestamp_and_clock_sequence() -> (u64, u16) {
+    let mut state = CLOCK_STATE.lock().unwrap();
+    let (last_ticks, last_seq) = *state;
+
+    let ticks = gregorian_ticks_now();
+
+    let seq = if last_ticks == 0 {
+        (Uuid::new_v4().as_u128() & 0x3fff) as u16
+    } else if ticks > last_ticks {
+        last_seq
+    } else {
+        last_seq.wrapping_add(1) & 0x3fff
+    };
+
+    *state = (ticks, seq);
+
+    (ticks, seq)
+}
+
 /// Generate a new nil `Uuid` with all zeros.
 ///
 /// # Safety
@@ -36,6 +88,141 @@ pub unsafe extern "C" fn ruuid_generate_version_4() -> *mut Uuid {
     Box::into_raw(Box::new(uuid))
 }
 
+/// Generate a new `Uuid` of version 7, a time-ordered layout as defined in
+/// RFC 9562: the top 48 bits are the current Unix time in milliseconds, the
+/// version and variant nibbles are set accordingly, and the remaining bits
+/// are random.
+///
+/// UUIDs generated on the same thread within the same millisecond keep their
+/// `rand_a` field monotonically increasing instead of redrawing it, so
+/// ordering by value matches ordering by creation time even at sub-millisecond
+/// resolution. The 12-bit `rand_a` field wraps from `0xfff` back to `0`; UUIDs
+/// generated across that wrap within the same millisecond do not sort in
+/// creation order.
+///
+/// # Safety
+///
+/// Make sure you destroy the Uuid with [`ruuid_destroy()`] once you are
+/// done with it.
+///
+/// [`ruuid_destroy()`]: fn.ruuid_destroy.html
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_generate_version_7() -> *mut Uuid {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let rand_a = LAST_V7.with(|last| {
+        let (last_millis, last_rand_a) = last.get();
+        let rand_a = if millis == last_millis {
+            last_rand_a.wrapping_add(1) & 0x0fff
+        } else {
+            (Uuid::new_v4().as_u128() & 0x0fff) as u16
+        };
+        last.set((millis, rand_a));
+        rand_a
+    });
+
+    let rand_b = *Uuid::new_v4().as_bytes();
+
+    let mut bytes = [0u8; 16];
+    bytes[0] = (millis >> 40) as u8;
+    bytes[1] = (millis >> 32) as u8;
+    bytes[2] = (millis >> 24) as u8;
+    bytes[3] = (millis >> 16) as u8;
+    bytes[4] = (millis >> 8) as u8;
+    bytes[5] = millis as u8;
+    bytes[6] = 0x70 | ((rand_a >> 8) as u8 & 0x0f);
+    bytes[7] = rand_a as u8;
+    bytes[8] = 0x80 | (rand_b[8] & 0x3f);
+    bytes[9..16].copy_from_slice(&rand_b[9..16]);
+
+    let uuid = Uuid::from_bytes(bytes);
+    Box::into_raw(Box::new(uuid))
+}
+
+/// Generate a new `Uuid` of version 1: a 60-bit Gregorian timestamp, a
+/// 14-bit clock sequence and the given 6-byte node id. `node` must point to
+/// 6 bytes, typically the server's MAC address or a configured site id.
+///
+/// The clock sequence is kept in a process-global counter, seeded randomly
+/// and bumped whenever the timestamp does not advance, so concurrent calls
+/// within the same 100-ns tick still produce unique UUIDs.
+///
+/// # Safety
+///
+/// Make sure you destroy the Uuid with [`ruuid_destroy()`] once you are
+/// done with it.
+///
+/// [`ruuid_destroy()`]: fn.ruuid_destroy.html
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_generate_version_1(node: *const u8) -> *mut Uuid {
+    if node.is_null() {
+        return ptr::null_mut();
+    }
+
+    let mut node_id = [0u8; 6];
+    node_id.copy_from_slice(slice::from_raw_parts(node, 6));
+
+    let (ticks, clock_seq) = next_timestamp_and_clock_sequence();
+
+    let time_low = (ticks & 0xffff_ffff) as u32;
+    let time_mid = ((ticks >> 32) & 0xffff) as u16;
+    let time_hi_and_version = (((ticks >> 48) & 0x0fff) as u16) | (1 << 12);
+
+    let mut bytes = [0u8; 16];
+    bytes[0..4].copy_from_slice(&time_low.to_be_bytes());
+    bytes[4..6].copy_from_slice(&time_mid.to_be_bytes());
+    bytes[6..8].copy_from_slice(&time_hi_and_version.to_be_bytes());
+    bytes[8] = 0x80 | ((clock_seq >> 8) as u8 & 0x3f);
+    bytes[9] = clock_seq as u8;
+    bytes[10..16].copy_from_slice(&node_id);
+
+    let uuid = Uuid::from_bytes(bytes);
+    Box::into_raw(Box::new(uuid))
+}
+
+/// Generate a new `Uuid` of version 6: the same fields as
+/// [`ruuid_generate_version_1()`], but with the timestamp reordered into
+/// big-endian most-significant-first order so the value sorts by creation
+/// time. `node` must point to 6 bytes.
+///
+/// # Safety
+///
+/// Make sure you destroy the Uuid with [`ruuid_destroy()`] once you are
+/// done with it.
+///
+/// [`ruuid_destroy()`]: fn.ruuid_destroy.html
+/// [`ruuid_generate_version_1()`]: fn.ruuid_generate_version_1.html
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_generate_version_6(node: *const u8) -> *mut Uuid {
+    if node.is_null() {
+        return ptr::null_mut();
+    }
+
+    let mut node_id = [0u8; 6];
+    node_id.copy_from_slice(slice::from_raw_parts(node, 6));
+
+    let (ticks, clock_seq) = next_timestamp_and_clock_sequence();
+
+    let mut bytes = [0u8; 16];
+    bytes[0] = (ticks >> 52) as u8;
+    bytes[1] = (ticks >> 44) as u8;
+    bytes[2] = (ticks >> 36) as u8;
+    bytes[3] = (ticks >> 28) as u8;
+    bytes[4] = (ticks >> 20) as u8;
+    bytes[5] = (ticks >> 12) as u8;
+    bytes[6] = 0x60 | (((ticks >> 8) & 0x0f) as u8);
+    bytes[7] = ticks as u8;
+    bytes[8] = 0x80 | ((clock_seq >> 8) as u8 & 0x3f);
+    bytes[9] = clock_seq as u8;
+    bytes[10..16].copy_from_slice(&node_id);
+
+    let uuid = Uuid::from_bytes(bytes);
+    Box::into_raw(Box::new(uuid))
+}
+
 /// Generate a new `Uuid` of version 5 with the SIP namespace and given name.
 ///
 /// # Safety
@@ -100,6 +287,110 @@ pub unsafe extern "C" fn ruuid_is_nil(uuid: *const Uuid) -> c_int {
     return 0;
 }
 
+/// Get the 4-bit version number of a `Uuid` (`0` for the nil UUID), or `-1`
+/// if `uuid` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_version(uuid: *const Uuid) -> c_int {
+    if uuid.is_null() {
+        return -1;
+    }
+
+    let uuid = &*uuid;
+
+    uuid.get_version_num() as c_int
+}
+
+/// Get the variant of a `Uuid` (`0` NCS, `2` RFC 4122, `6` Microsoft, `7`
+/// reserved/future), or `-1` if `uuid` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_variant(uuid: *const Uuid) -> c_int {
+    if uuid.is_null() {
+        return -1;
+    }
+
+    let uuid = &*uuid;
+
+    match uuid.get_variant() {
+        uuid::Variant::NCS => 0,
+        uuid::Variant::RFC4122 => 2,
+        uuid::Variant::Microsoft => 6,
+        uuid::Variant::Future => 7,
+        _ => -1,
+    }
+}
+
+/// Extract the creation timestamp embedded in a time-based `Uuid` (v1, v6 or
+/// v7) as Unix milliseconds, writing it to `*millis`.
+///
+/// Returns `-1` for null pointers or for any version that does not carry a
+/// usable timestamp.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_timestamp_millis(uuid: *const Uuid, millis: *mut u64) -> c_int {
+    if uuid.is_null() || millis.is_null() {
+        return -1;
+    }
+
+    let uuid = &*uuid;
+    let bytes = uuid.as_bytes();
+
+    let value = match uuid.get_version_num() {
+        7 => {
+            ((bytes[0] as u64) << 40)
+                | ((bytes[1] as u64) << 32)
+                | ((bytes[2] as u64) << 24)
+                | ((bytes[3] as u64) << 16)
+                | ((bytes[4] as u64) << 8)
+                | (bytes[5] as u64)
+        }
+        version @ (1 | 6) => {
+            let ticks = if version == 6 {
+                ((bytes[0] as u64) << 52)
+                    | ((bytes[1] as u64) << 44)
+                    | ((bytes[2] as u64) << 36)
+                    | ((bytes[3] as u64) << 28)
+                    | ((bytes[4] as u64) << 20)
+                    | ((bytes[5] as u64) << 12)
+                    | (((bytes[6] & 0x0f) as u64) << 8)
+                    | (bytes[7] as u64)
+            } else {
+                let time_low = ((bytes[0] as u64) << 24)
+                    | ((bytes[1] as u64) << 16)
+                    | ((bytes[2] as u64) << 8)
+                    | (bytes[3] as u64);
+                let time_mid = ((bytes[4] as u64) << 8) | (bytes[5] as u64);
+                let time_hi = (((bytes[6] & 0x0f) as u64) << 8) | (bytes[7] as u64);
+
+                (time_hi << 48) | (time_mid << 32) | time_low
+            };
+
+            if ticks < GREGORIAN_EPOCH_OFFSET_100NS {
+                return -1;
+            }
+
+            (ticks - GREGORIAN_EPOCH_OFFSET_100NS) / 10_000
+        }
+        _ => return -1,
+    };
+
+    *millis = value;
+
+    0
+}
+
+/// Copy an already-encoded uuid string into a user-provided buffer, returning
+/// the number of bytes copied, or `-1` if the buffer is too small.
+unsafe fn copy_encoded(encoded: &str, buffer: *mut c_char, length: size_t) -> c_int {
+    let buffer: &mut [u8] = slice::from_raw_parts_mut(buffer as *mut u8, length as usize);
+
+    if buffer.len() < encoded.len() {
+        return -1;
+    }
+
+    ptr::copy_nonoverlapping(encoded.as_ptr(), buffer.as_mut_ptr(), encoded.len());
+
+    encoded.len() as c_int
+}
+
 /// Copy the uuid in simple form into a user-provided buffer, returning the number of
 /// bytes copied.
 ///
@@ -111,18 +402,27 @@ pub unsafe extern "C" fn ruuid_get_simple(uuid: *const Uuid, buffer: *mut c_char
     }
 
     let uuid = &*uuid;
-    let buffer: &mut [u8] = slice::from_raw_parts_mut(buffer as *mut u8,
-                                                      length as usize);
+    let mut encode_buf = [0u8; uuid::fmt::Simple::LENGTH];
+    let encoded = uuid.simple().encode_lower(&mut encode_buf);
 
-    let string = uuid.simple().to_string();
+    copy_encoded(encoded, buffer, length)
+}
 
-    if buffer.len() < string.len() {
+/// Copy the uuid in simple form, using uppercase hex digits, into a
+/// user-provided buffer, returning the number of bytes copied.
+///
+/// If an error is encountered, this returns `-1`.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_simple_upper(uuid: *const Uuid, buffer: *mut c_char, length: size_t) -> c_int {
+    if uuid.is_null() || buffer.is_null() {
         return -1;
     }
 
-    ptr::copy_nonoverlapping(string.as_ptr(), buffer.as_mut_ptr(), string.len());
+    let uuid = &*uuid;
+    let mut encode_buf = [0u8; uuid::fmt::Simple::LENGTH];
+    let encoded = uuid.simple().encode_upper(&mut encode_buf);
 
-    string.len() as c_int
+    copy_encoded(encoded, buffer, length)
 }
 
 /// Copy the uuid in hyphenated form into a user-provided buffer, returning the number of
@@ -136,18 +436,27 @@ pub unsafe extern "C" fn ruuid_get_hyphenated(uuid: *const Uuid, buffer: *mut c_
     }
 
     let uuid = &*uuid;
-    let buffer: &mut [u8] = slice::from_raw_parts_mut(buffer as *mut u8,
-                                                      length as usize);
+    let mut encode_buf = [0u8; uuid::fmt::Hyphenated::LENGTH];
+    let encoded = uuid.hyphenated().encode_lower(&mut encode_buf);
 
-    let string = uuid.hyphenated().to_string();
+    copy_encoded(encoded, buffer, length)
+}
 
-    if buffer.len() < string.len() {
+/// Copy the uuid in hyphenated form, using uppercase hex digits, into a
+/// user-provided buffer, returning the number of bytes copied.
+///
+/// If an error is encountered, this returns `-1`.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_hyphenated_upper(uuid: *const Uuid, buffer: *mut c_char, length: size_t) -> c_int {
+    if uuid.is_null() || buffer.is_null() {
         return -1;
     }
 
-    ptr::copy_nonoverlapping(string.as_ptr(), buffer.as_mut_ptr(), string.len());
+    let uuid = &*uuid;
+    let mut encode_buf = [0u8; uuid::fmt::Hyphenated::LENGTH];
+    let encoded = uuid.hyphenated().encode_upper(&mut encode_buf);
 
-    string.len() as c_int
+    copy_encoded(encoded, buffer, length)
 }
 
 /// Copy the uuid in urn form into a user-provided buffer, returning the number of
@@ -161,18 +470,27 @@ pub unsafe extern "C" fn ruuid_get_urn(uuid: *const Uuid, buffer: *mut c_char, l
     }
 
     let uuid = &*uuid;
-    let buffer: &mut [u8] = slice::from_raw_parts_mut(buffer as *mut u8,
-                                                      length as usize);
+    let mut encode_buf = [0u8; uuid::fmt::Urn::LENGTH];
+    let encoded = uuid.urn().encode_lower(&mut encode_buf);
 
-    let string = uuid.urn().to_string();
+    copy_encoded(encoded, buffer, length)
+}
 
-    if buffer.len() < string.len() {
+/// Copy the uuid in urn form, using uppercase hex digits, into a
+/// user-provided buffer, returning the number of bytes copied.
+///
+/// If an error is encountered, this returns `-1`.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_urn_upper(uuid: *const Uuid, buffer: *mut c_char, length: size_t) -> c_int {
+    if uuid.is_null() || buffer.is_null() {
         return -1;
     }
 
-    ptr::copy_nonoverlapping(string.as_ptr(), buffer.as_mut_ptr(), string.len());
+    let uuid = &*uuid;
+    let mut encode_buf = [0u8; uuid::fmt::Urn::LENGTH];
+    let encoded = uuid.urn().encode_upper(&mut encode_buf);
 
-    string.len() as c_int
+    copy_encoded(encoded, buffer, length)
 }
 
 /// Copy the uuid in braced form into a user-provided buffer, returning the number of
@@ -186,18 +504,142 @@ pub unsafe extern "C" fn ruuid_get_braced(uuid: *const Uuid, buffer: *mut c_char
     }
 
     let uuid = &*uuid;
-    let buffer: &mut [u8] = slice::from_raw_parts_mut(buffer as *mut u8,
-                                                      length as usize);
+    let mut encode_buf = [0u8; uuid::fmt::Braced::LENGTH];
+    let encoded = uuid.braced().encode_lower(&mut encode_buf);
+
+    copy_encoded(encoded, buffer, length)
+}
+
+/// Copy the uuid in braced form, using uppercase hex digits, into a
+/// user-provided buffer, returning the number of bytes copied.
+///
+/// If an error is encountered, this returns `-1`.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_braced_upper(uuid: *const Uuid, buffer: *mut c_char, length: size_t) -> c_int {
+    if uuid.is_null() || buffer.is_null() {
+        return -1;
+    }
+
+    let uuid = &*uuid;
+    let mut encode_buf = [0u8; uuid::fmt::Braced::LENGTH];
+    let encoded = uuid.braced().encode_upper(&mut encode_buf);
+
+    copy_encoded(encoded, buffer, length)
+}
+
+/// Build a `Uuid` from the big-endian field layout used by Windows GUIDs
+/// (`{Data1,Data2,Data3,Data4[8]}`). `d4` must point to 8 bytes.
+///
+/// # Safety
+///
+/// Make sure you destroy the Uuid with [`ruuid_destroy()`] once you are
+/// done with it.
+///
+/// [`ruuid_destroy()`]: fn.ruuid_destroy.html
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_from_fields(d1: u32, d2: u16, d3: u16, d4: *const u8) -> *mut Uuid {
+    if d4.is_null() {
+        return ptr::null_mut();
+    }
 
-    let string = uuid.braced().to_string();
+    let mut d4_bytes = [0u8; 8];
+    d4_bytes.copy_from_slice(slice::from_raw_parts(d4, 8));
 
-    if buffer.len() < string.len() {
+    let uuid = Uuid::from_fields(d1, d2, d3, &d4_bytes);
+    Box::into_raw(Box::new(uuid))
+}
+
+/// Build a `Uuid` from the same field layout as [`ruuid_from_fields()`], but
+/// with `d1`, `d2` and `d3` given in little-endian byte order. `d4` must
+/// point to 8 bytes.
+///
+/// # Safety
+///
+/// Make sure you destroy the Uuid with [`ruuid_destroy()`] once you are
+/// done with it.
+///
+/// [`ruuid_destroy()`]: fn.ruuid_destroy.html
+/// [`ruuid_from_fields()`]: fn.ruuid_from_fields.html
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_from_fields_le(d1: u32, d2: u16, d3: u16, d4: *const u8) -> *mut Uuid {
+    if d4.is_null() {
+        return ptr::null_mut();
+    }
+
+    let mut d4_bytes = [0u8; 8];
+    d4_bytes.copy_from_slice(slice::from_raw_parts(d4, 8));
+
+    let uuid = Uuid::from_fields_le(d1, d2, d3, &d4_bytes);
+    Box::into_raw(Box::new(uuid))
+}
+
+/// Decompose a `Uuid` into the big-endian `{Data1,Data2,Data3,Data4[8]}`
+/// field layout used by Windows GUIDs. `d4` must point to 8 writable bytes.
+///
+/// Returns `-1` on a null pointer, `0` otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_as_fields(
+    uuid: *const Uuid,
+    d1: *mut u32,
+    d2: *mut u16,
+    d3: *mut u16,
+    d4: *mut u8,
+) -> c_int {
+    if uuid.is_null() || d1.is_null() || d2.is_null() || d3.is_null() || d4.is_null() {
         return -1;
     }
 
-    ptr::copy_nonoverlapping(string.as_ptr(), buffer.as_mut_ptr(), string.len());
+    let uuid = &*uuid;
+    let (field1, field2, field3, field4) = uuid.as_fields();
+
+    *d1 = field1;
+    *d2 = field2;
+    *d3 = field3;
 
-    string.len() as c_int
+    let d4_buffer: &mut [u8] = slice::from_raw_parts_mut(d4, 8);
+    d4_buffer.copy_from_slice(field4);
+
+    0
+}
+
+/// Build a `Uuid` from its canonical 16-octet big-endian representation.
+/// `length` must be exactly 16, otherwise this returns a null pointer.
+///
+/// # Safety
+///
+/// Make sure you destroy the Uuid with [`ruuid_destroy()`] once you are
+/// done with it.
+///
+/// [`ruuid_destroy()`]: fn.ruuid_destroy.html
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_from_bytes(bytes: *const u8, length: size_t) -> *mut Uuid {
+    if bytes.is_null() || length != 16 {
+        return ptr::null_mut();
+    }
+
+    let mut buf = [0u8; 16];
+    buf.copy_from_slice(slice::from_raw_parts(bytes, 16));
+
+    let uuid = Uuid::from_bytes(buf);
+    Box::into_raw(Box::new(uuid))
+}
+
+/// Copy the canonical 16-octet big-endian representation of a `Uuid` into a
+/// user-provided buffer, returning the number of bytes copied.
+///
+/// If an error is encountered, this returns `-1`.
+#[no_mangle]
+pub unsafe extern "C" fn ruuid_get_bytes(uuid: *const Uuid, buffer: *mut u8, length: size_t) -> c_int {
+    if uuid.is_null() || buffer.is_null() || length < 16 {
+        return -1;
+    }
+
+    let uuid = &*uuid;
+    let buffer: &mut [u8] = slice::from_raw_parts_mut(buffer, 16);
+
+    buffer.copy_from_slice(uuid.as_bytes());
+
+    16
 }
 
 /// Destroy a `Uuid` once you are done with it.
@@ -206,4 +648,112 @@ pub unsafe extern "C" fn ruuid_destroy(uuid: *mut Uuid) {
     if !uuid.is_null() {
         drop(Box::from_raw(uuid));
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+
+    unsafe fn assert_recent_timestamp(uuid: *const Uuid) {
+        let mut millis = 0u64;
+        assert_eq!(ruuid_get_timestamp_millis(uuid, &mut millis), 0);
+
+        let now = now_millis();
+        assert!(millis <= now, "embedded timestamp {millis} is in the future ({now})");
+        assert!(now - millis < 5_000, "embedded timestamp {millis} is too far from now ({now})");
+    }
+
+    #[test]
+    fn version_7_round_trips_version_variant_and_timestamp() {
+        unsafe {
+            let uuid = ruuid_generate_version_7();
+            assert!(!uuid.is_null());
+
+            assert_eq!(ruuid_get_version(uuid), 7);
+            assert_eq!(ruuid_get_variant(uuid), 2);
+            assert_recent_timestamp(uuid);
+
+            ruuid_destroy(uuid);
+        }
+    }
+
+    #[test]
+    fn version_1_round_trips_version_variant_timestamp_and_node() {
+        unsafe {
+            let node = [0xaau8, 0xbb, 0xcc, 0xdd, 0xee, 0xff];
+            let uuid = ruuid_generate_version_1(node.as_ptr());
+            assert!(!uuid.is_null());
+
+            assert_eq!(ruuid_get_version(uuid), 1);
+            assert_eq!(ruuid_get_variant(uuid), 2);
+            assert_recent_timestamp(uuid);
+
+            let mut bytes = [0u8; 16];
+            assert_eq!(ruuid_get_bytes(uuid, bytes.as_mut_ptr(), bytes.len()), 16);
+            assert_eq!(&bytes[10..16], &node);
+
+            ruuid_destroy(uuid);
+        }
+    }
+
+    #[test]
+    fn version_6_round_trips_version_variant_timestamp_and_node() {
+        unsafe {
+            let node = [0x11u8, 0x22, 0x33, 0x44, 0x55, 0x66];
+            let uuid = ruuid_generate_version_6(node.as_ptr());
+            assert!(!uuid.is_null());
+
+            assert_eq!(ruuid_get_version(uuid), 6);
+            assert_eq!(ruuid_get_variant(uuid), 2);
+            assert_recent_timestamp(uuid);
+
+            let mut bytes = [0u8; 16];
+            assert_eq!(ruuid_get_bytes(uuid, bytes.as_mut_ptr(), bytes.len()), 16);
+            assert_eq!(&bytes[10..16], &node);
+
+            ruuid_destroy(uuid);
+        }
+    }
+
+    #[test]
+    fn nil_and_random_uuids_report_no_timestamp() {
+        unsafe {
+            let nil = ruuid_generate_nil();
+            assert_eq!(ruuid_get_version(nil), 0);
+
+            let mut millis = 0u64;
+            assert_eq!(ruuid_get_timestamp_millis(nil, &mut millis), -1);
+
+            ruuid_destroy(nil);
+
+            let v4 = ruuid_generate_version_4();
+            assert_eq!(ruuid_get_version(v4), 4);
+            assert_eq!(ruuid_get_timestamp_millis(v4, &mut millis), -1);
+
+            ruuid_destroy(v4);
+        }
+    }
+
+    #[test]
+    fn version_1_clock_sequence_keeps_bursts_unique() {
+        unsafe {
+            let node = [0u8; 6];
+            let mut seen = std::collections::HashSet::new();
+
+            for _ in 0..5_000 {
+                let uuid = ruuid_generate_version_1(node.as_ptr());
+                let mut bytes = [0u8; 16];
+                assert_eq!(ruuid_get_bytes(uuid, bytes.as_mut_ptr(), bytes.len()), 16);
+                assert!(seen.insert(bytes), "duplicate v1 uuid generated");
+                ruuid_destroy(uuid);
+            }
+        }
+    }
 }
\ No newline at end of file